@@ -11,11 +11,19 @@ mod dag;
 mod topological_sort;
 mod cycle_detection;
 mod path_finding;
+mod scc;
+mod feedback_arc_set;
+mod runs;
+mod bellman_ford;
 
 use dag::{build_dag_from_relationships, Relationship};
 use topological_sort::*;
 use cycle_detection::*;
 use path_finding::*;
+use scc::*;
+use feedback_arc_set::*;
+use runs::*;
+use bellman_ford::*;
 
 /// Graph edge structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,6 +48,12 @@ pub struct Graph {
 pub struct TopologicalSortResult {
     pub sorted: Vec<String>,
     pub has_cycle: bool,
+    /// Nodes that still had non-zero in-degree when Kahn's algorithm stalled.
+    /// `None` when `has_cycle` is false.
+    pub remaining: Option<Vec<String>>,
+    /// One concrete cycle extracted from the residual subgraph, in visit order.
+    /// `None` when `has_cycle` is false.
+    pub cycle: Option<Vec<String>>,
 }
 
 /// Cycle detection result
@@ -59,6 +73,43 @@ pub struct PathResult {
     pub distance: Option<f64>,
 }
 
+/// Strongly connected components result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SccResult {
+    pub components: Vec<Vec<String>>,
+}
+
+/// Feedback arc set result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedbackArcSetResult {
+    pub removed: Vec<Edge>,
+}
+
+/// Multiple-paths result, shared by `all_simple_paths` and `k_shortest_paths`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PathsResult {
+    pub paths: Vec<Vec<String>>,
+}
+
+/// Maximal linear chains result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunsResult {
+    pub runs: Vec<Vec<String>>,
+}
+
+/// Bellman-Ford single-source shortest paths result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BellmanFordResult {
+    pub distances: std::collections::HashMap<String, f64>,
+    pub has_negative_cycle: bool,
+    pub negative_cycle: Vec<String>,
+}
+
 /// Build a DAG from edges and perform topological sort
 /// 
 /// # Arguments
@@ -74,14 +125,20 @@ pub fn topological_sort(graph_json: &str) -> String {
             return serde_json::to_string(&TopologicalSortResult {
                 sorted: vec![],
                 has_cycle: true,
+                remaining: None,
+                cycle: None,
             })
-            .unwrap_or_else(|_| "{\"sorted\":[],\"hasCycle\":true}".to_string());
+            .unwrap_or_else(|_| {
+                "{\"sorted\":[],\"hasCycle\":true,\"remaining\":null,\"cycle\":null}".to_string()
+            });
         }
     };
 
     let result = compute_topological_sort(&graph);
-    
-    serde_json::to_string(&result).unwrap_or_else(|_| "{\"sorted\":[],\"hasCycle\":true}".to_string())
+
+    serde_json::to_string(&result).unwrap_or_else(|_| {
+        "{\"sorted\":[],\"hasCycle\":true,\"remaining\":null,\"cycle\":null}".to_string()
+    })
 }
 
 /// Detect cycles in a directed graph
@@ -137,8 +194,224 @@ pub fn find_path(graph_json: &str, from: &str, to: &str) -> String {
     serde_json::to_string(&result).unwrap_or_else(|_| "{\"path\":[],\"exists\":false,\"distance\":null}".to_string())
 }
 
+/// Find the minimum-weight path between two nodes using Dijkstra's algorithm
+///
+/// # Arguments
+/// * `graph_json` - JSON string of Graph structure
+/// * `from` - Starting node
+/// * `to` - Target node
+///
+/// # Returns
+/// JSON string of PathResult. Edges with negative weight cause `exists: false`,
+/// since Dijkstra's algorithm requires non-negative weights.
+#[wasm_bindgen]
+pub fn shortest_path(graph_json: &str, from: &str, to: &str) -> String {
+    let graph: Graph = match serde_json::from_str(graph_json) {
+        Ok(g) => g,
+        Err(_) => {
+            return serde_json::to_string(&PathResult {
+                path: vec![],
+                exists: false,
+                distance: None,
+            })
+            .unwrap_or_else(|_| "{\"path\":[],\"exists\":false,\"distance\":null}".to_string());
+        }
+    };
+
+    let result = dijkstra_shortest_path(&graph, from, to);
+
+    serde_json::to_string(&result).unwrap_or_else(|_| "{\"path\":[],\"exists\":false,\"distance\":null}".to_string())
+}
+
+/// Compute the strongly connected components of a directed graph using Tarjan's algorithm
+///
+/// # Arguments
+/// * `graph_json` - JSON string of Graph structure
+///
+/// # Returns
+/// JSON string of SccResult
+#[wasm_bindgen]
+pub fn strongly_connected_components(graph_json: &str) -> String {
+    let graph: Graph = match serde_json::from_str(graph_json) {
+        Ok(g) => g,
+        Err(_) => {
+            return serde_json::to_string(&SccResult { components: vec![] })
+                .unwrap_or_else(|_| "{\"components\":[]}".to_string());
+        }
+    };
+
+    let result = compute_strongly_connected_components(&graph);
+
+    serde_json::to_string(&result).unwrap_or_else(|_| "{\"components\":[]}".to_string())
+}
+
+/// Collapse each strongly connected component of a graph into a single
+/// super-node, producing an acyclic graph that can be fed into `topological_sort`
+///
+/// # Arguments
+/// * `graph_json` - JSON string of Graph structure
+///
+/// # Returns
+/// JSON string of Graph structure
+#[wasm_bindgen]
+pub fn condense(graph_json: &str) -> String {
+    let graph: Graph = match serde_json::from_str(graph_json) {
+        Ok(g) => g,
+        Err(_) => {
+            return serde_json::to_string(&Graph {
+                nodes: vec![],
+                edges: vec![],
+            })
+            .unwrap_or_else(|_| "{\"nodes\":[],\"edges\":[]}".to_string());
+        }
+    };
+
+    let result = scc::condense_graph(&graph);
+
+    serde_json::to_string(&result).unwrap_or_else(|_| "{\"nodes\":[],\"edges\":[]}".to_string())
+}
+
+/// Compute a set of edges whose removal makes a cyclic graph acyclic, using the
+/// greedy Eades-Lin-Smyth heuristic
+///
+/// # Arguments
+/// * `graph_json` - JSON string of Graph structure
+///
+/// # Returns
+/// JSON string of FeedbackArcSetResult
+#[wasm_bindgen]
+pub fn greedy_feedback_arc_set(graph_json: &str) -> String {
+    let graph: Graph = match serde_json::from_str(graph_json) {
+        Ok(g) => g,
+        Err(_) => {
+            return serde_json::to_string(&FeedbackArcSetResult { removed: vec![] })
+                .unwrap_or_else(|_| "{\"removed\":[]}".to_string());
+        }
+    };
+
+    let result = compute_feedback_arc_set(&graph);
+
+    serde_json::to_string(&result).unwrap_or_else(|_| "{\"removed\":[]}".to_string())
+}
+
+/// Enumerate every loop-free path between two nodes with at most `max_len` edges
+///
+/// # Arguments
+/// * `graph_json` - JSON string of Graph structure
+/// * `from` - Starting node
+/// * `to` - Target node
+/// * `max_len` - Maximum number of edges in a returned path
+///
+/// # Returns
+/// JSON string of PathsResult
+#[wasm_bindgen]
+pub fn all_simple_paths(graph_json: &str, from: &str, to: &str, max_len: usize) -> String {
+    let graph: Graph = match serde_json::from_str(graph_json) {
+        Ok(g) => g,
+        Err(_) => {
+            return serde_json::to_string(&PathsResult { paths: vec![] })
+                .unwrap_or_else(|_| "{\"paths\":[]}".to_string());
+        }
+    };
+
+    let result = find_all_simple_paths(&graph, from, to, max_len);
+
+    serde_json::to_string(&result).unwrap_or_else(|_| "{\"paths\":[]}".to_string())
+}
+
+/// Find the `k` lowest-weight paths between two nodes, honoring `Edge::weight`
+///
+/// # Arguments
+/// * `graph_json` - JSON string of Graph structure
+/// * `from` - Starting node
+/// * `to` - Target node
+/// * `k` - Number of paths to return
+///
+/// # Returns
+/// JSON string of PathsResult
+#[wasm_bindgen]
+pub fn k_shortest_paths(graph_json: &str, from: &str, to: &str, k: usize) -> String {
+    let graph: Graph = match serde_json::from_str(graph_json) {
+        Ok(g) => g,
+        Err(_) => {
+            return serde_json::to_string(&PathsResult { paths: vec![] })
+                .unwrap_or_else(|_| "{\"paths\":[]}".to_string());
+        }
+    };
+
+    let result = compute_k_shortest_paths(&graph, from, to, k);
+
+    serde_json::to_string(&result).unwrap_or_else(|_| "{\"paths\":[]}".to_string())
+}
+
+/// Collapse maximal chains of a DAG into grouped runs, restricted to an allowed
+/// set of nodes; a run only continues into a successor that has exactly one
+/// predecessor, while a node with several successors may still extend a run
+///
+/// # Arguments
+/// * `graph_json` - JSON string of Graph structure
+/// * `allowed_nodes_json` - JSON array of node ids eligible to appear in a run;
+///   an empty array means every node is eligible
+///
+/// # Returns
+/// JSON string of RunsResult. Empty if the graph contains a cycle.
+#[wasm_bindgen]
+pub fn collect_runs(graph_json: &str, allowed_nodes_json: &str) -> String {
+    let graph: Graph = match serde_json::from_str(graph_json) {
+        Ok(g) => g,
+        Err(_) => {
+            return serde_json::to_string(&RunsResult { runs: vec![] })
+                .unwrap_or_else(|_| "{\"runs\":[]}".to_string());
+        }
+    };
+
+    let allowed: std::collections::HashSet<String> = match serde_json::from_str(allowed_nodes_json) {
+        Ok(a) => a,
+        Err(_) => {
+            return serde_json::to_string(&RunsResult { runs: vec![] })
+                .unwrap_or_else(|_| "{\"runs\":[]}".to_string());
+        }
+    };
+
+    let result = compute_runs(&graph, &allowed);
+
+    serde_json::to_string(&result).unwrap_or_else(|_| "{\"runs\":[]}".to_string())
+}
+
+/// Compute single-source shortest distances with Bellman-Ford, tolerating
+/// negative edge weights and detecting negative-weight cycles
+///
+/// # Arguments
+/// * `graph_json` - JSON string of Graph structure
+/// * `from` - Starting node
+///
+/// # Returns
+/// JSON string of BellmanFordResult
+#[wasm_bindgen]
+pub fn shortest_path_bellman_ford(graph_json: &str, from: &str) -> String {
+    let graph: Graph = match serde_json::from_str(graph_json) {
+        Ok(g) => g,
+        Err(_) => {
+            return serde_json::to_string(&BellmanFordResult {
+                distances: std::collections::HashMap::new(),
+                has_negative_cycle: false,
+                negative_cycle: vec![],
+            })
+            .unwrap_or_else(|_| {
+                "{\"distances\":{},\"hasNegativeCycle\":false,\"negativeCycle\":[]}".to_string()
+            });
+        }
+    };
+
+    let result = compute_bellman_ford(&graph, from);
+
+    serde_json::to_string(&result).unwrap_or_else(|_| {
+        "{\"distances\":{},\"hasNegativeCycle\":false,\"negativeCycle\":[]}".to_string()
+    })
+}
+
 /// Build a DAG from relationships
-/// 
+///
 /// # Arguments
 /// * `relationships_json` - JSON string of relationship array (each with from, to, confidence)
 /// 
@@ -1,5 +1,6 @@
-use super::{Graph, PathResult};
-use std::collections::{HashMap, HashSet, VecDeque};
+use super::{Graph, PathResult, PathsResult};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 
 /// Find path between two nodes using BFS
 pub fn find_path_between_nodes(graph: &Graph, from: &str, to: &str) -> PathResult {
@@ -61,10 +62,385 @@ pub fn find_path_between_nodes(graph: &Graph, from: &str, to: &str) -> PathResul
     }
 }
 
+/// Min-heap entry for Dijkstra's algorithm, ordered by ascending distance
+#[derive(Debug, Clone, PartialEq)]
+struct HeapEntry {
+    distance: f64,
+    node: String,
+}
+
+impl Eq for HeapEntry {}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so BinaryHeap (a max-heap) pops the smallest distance first
+        other
+            .distance
+            .partial_cmp(&self.distance)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Find the minimum-weight path between two nodes using Dijkstra's algorithm
+///
+/// Unlike `find_path_between_nodes`, which returns the fewest-hop path, this
+/// respects `Edge::weight` and returns the path of least total weight.
+/// Negative weights are rejected since Dijkstra's algorithm requires them to
+/// be non-negative.
+pub fn dijkstra_shortest_path(graph: &Graph, from: &str, to: &str) -> PathResult {
+    if graph.edges.iter().any(|edge| edge.weight.unwrap_or(1.0) < 0.0) {
+        return PathResult {
+            path: vec![],
+            exists: false,
+            distance: None,
+        };
+    }
+
+    if from == to {
+        return PathResult {
+            path: vec![from.to_string()],
+            exists: true,
+            distance: Some(0.0),
+        };
+    }
+
+    // Build adjacency list
+    let mut adjacency: HashMap<String, Vec<(String, f64)>> = HashMap::new();
+
+    for node in &graph.nodes {
+        adjacency.insert(node.clone(), Vec::new());
+    }
+
+    for edge in &graph.edges {
+        let weight = edge.weight.unwrap_or(1.0);
+        adjacency
+            .entry(edge.from.clone())
+            .or_insert_with(Vec::new)
+            .push((edge.to.clone(), weight));
+    }
+
+    let mut dist: HashMap<String, f64> = HashMap::new();
+    let mut predecessor: HashMap<String, String> = HashMap::new();
+    let mut heap: BinaryHeap<HeapEntry> = BinaryHeap::new();
+
+    dist.insert(from.to_string(), 0.0);
+    heap.push(HeapEntry {
+        distance: 0.0,
+        node: from.to_string(),
+    });
+
+    while let Some(HeapEntry { distance, node }) = heap.pop() {
+        if node == to {
+            let mut path = vec![to.to_string()];
+            let mut current = to.to_string();
+            while let Some(prev) = predecessor.get(&current) {
+                path.push(prev.clone());
+                current = prev.clone();
+            }
+            path.reverse();
+
+            return PathResult {
+                path,
+                exists: true,
+                distance: Some(distance),
+            };
+        }
+
+        // Skip stale heap entries superseded by a shorter distance already found
+        if distance > *dist.get(&node).unwrap_or(&f64::INFINITY) {
+            continue;
+        }
+
+        if let Some(neighbors) = adjacency.get(&node) {
+            for (neighbor, weight) in neighbors {
+                let candidate = distance + weight;
+                if candidate < *dist.get(neighbor).unwrap_or(&f64::INFINITY) {
+                    dist.insert(neighbor.clone(), candidate);
+                    predecessor.insert(neighbor.clone(), node.clone());
+                    heap.push(HeapEntry {
+                        distance: candidate,
+                        node: neighbor.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    PathResult {
+        path: vec![],
+        exists: false,
+        distance: None,
+    }
+}
+
+/// Enumerate every loop-free path from `from` to `to` with at most `max_len` edges
+///
+/// Uses backtracking DFS: push the current node onto the in-progress path,
+/// recurse into unvisited neighbors, record the path when `to` is reached,
+/// then pop and unmark the node on return so siblings can reuse it.
+pub fn find_all_simple_paths(graph: &Graph, from: &str, to: &str, max_len: usize) -> PathsResult {
+    let mut adjacency: HashMap<String, Vec<String>> = HashMap::new();
+    for node in &graph.nodes {
+        adjacency.insert(node.clone(), Vec::new());
+    }
+    for edge in &graph.edges {
+        adjacency
+            .entry(edge.from.clone())
+            .or_insert_with(Vec::new)
+            .push(edge.to.clone());
+    }
+
+    let mut paths: Vec<Vec<String>> = Vec::new();
+    let mut visited: HashSet<String> = HashSet::new();
+    let mut path: Vec<String> = Vec::new();
+
+    visited.insert(from.to_string());
+    path.push(from.to_string());
+    walk_simple_paths(&adjacency, from, to, max_len, &mut visited, &mut path, &mut paths);
+
+    PathsResult { paths }
+}
+
+fn walk_simple_paths(
+    adjacency: &HashMap<String, Vec<String>>,
+    current: &str,
+    to: &str,
+    remaining_len: usize,
+    visited: &mut HashSet<String>,
+    path: &mut Vec<String>,
+    paths: &mut Vec<Vec<String>>,
+) {
+    if current == to {
+        paths.push(path.clone());
+        return;
+    }
+
+    if remaining_len == 0 {
+        return;
+    }
+
+    if let Some(neighbors) = adjacency.get(current) {
+        for neighbor in neighbors {
+            if !visited.contains(neighbor) {
+                visited.insert(neighbor.clone());
+                path.push(neighbor.clone());
+
+                walk_simple_paths(adjacency, neighbor, to, remaining_len - 1, visited, path, paths);
+
+                path.pop();
+                visited.remove(neighbor);
+            }
+        }
+    }
+}
+
+/// A partial path explored while searching for the k shortest paths
+#[derive(Debug, Clone, PartialEq)]
+struct PartialPath {
+    distance: f64,
+    node: String,
+    path: Vec<String>,
+    used_edges: HashSet<(String, String)>,
+}
+
+impl Eq for PartialPath {}
+
+impl Ord for PartialPath {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .partial_cmp(&self.distance)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for PartialPath {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Find the `k` lowest-weight paths from `from` to `to`, honoring `Edge::weight`
+///
+/// A variant of Dijkstra that keeps a min-heap of partial paths rather than a
+/// single best distance per node, popping up to `k` completions to `to`.
+/// The same node may be revisited across a path (to allow exploring detours),
+/// but a path never reuses an edge it has already taken.
+pub fn compute_k_shortest_paths(graph: &Graph, from: &str, to: &str, k: usize) -> PathsResult {
+    let mut adjacency: HashMap<String, Vec<(String, f64)>> = HashMap::new();
+    for node in &graph.nodes {
+        adjacency.insert(node.clone(), Vec::new());
+    }
+    for edge in &graph.edges {
+        let weight = edge.weight.unwrap_or(1.0);
+        adjacency
+            .entry(edge.from.clone())
+            .or_insert_with(Vec::new)
+            .push((edge.to.clone(), weight));
+    }
+
+    let mut heap: BinaryHeap<PartialPath> = BinaryHeap::new();
+    heap.push(PartialPath {
+        distance: 0.0,
+        node: from.to_string(),
+        path: vec![from.to_string()],
+        used_edges: HashSet::new(),
+    });
+
+    let mut paths: Vec<Vec<String>> = Vec::new();
+
+    while let Some(PartialPath { distance, node, path, used_edges }) = heap.pop() {
+        if paths.len() >= k {
+            break;
+        }
+
+        if node == to {
+            paths.push(path);
+            continue;
+        }
+
+        if let Some(neighbors) = adjacency.get(&node) {
+            for (neighbor, weight) in neighbors {
+                let edge_key = (node.clone(), neighbor.clone());
+                if used_edges.contains(&edge_key) {
+                    continue;
+                }
+
+                let mut next_used_edges = used_edges.clone();
+                next_used_edges.insert(edge_key);
+                let mut next_path = path.clone();
+                next_path.push(neighbor.clone());
+
+                heap.push(PartialPath {
+                    distance: distance + weight,
+                    node: neighbor.clone(),
+                    path: next_path,
+                    used_edges: next_used_edges,
+                });
+            }
+        }
+    }
+
+    PathsResult { paths }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_all_simple_paths_finds_every_route() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string(), "D".to_string()],
+            edges: vec![
+                super::super::Edge { from: "A".to_string(), to: "B".to_string(), weight: None },
+                super::super::Edge { from: "A".to_string(), to: "C".to_string(), weight: None },
+                super::super::Edge { from: "B".to_string(), to: "D".to_string(), weight: None },
+                super::super::Edge { from: "C".to_string(), to: "D".to_string(), weight: None },
+            ],
+        };
+
+        let result = find_all_simple_paths(&graph, "A", "D", 10);
+        assert_eq!(result.paths.len(), 2);
+    }
+
+    #[test]
+    fn test_all_simple_paths_respects_max_len() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            edges: vec![
+                super::super::Edge { from: "A".to_string(), to: "B".to_string(), weight: None },
+                super::super::Edge { from: "B".to_string(), to: "C".to_string(), weight: None },
+            ],
+        };
+
+        let result = find_all_simple_paths(&graph, "A", "C", 1);
+        assert!(result.paths.is_empty());
+    }
+
+    #[test]
+    fn test_k_shortest_paths_orders_by_weight() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            edges: vec![
+                super::super::Edge { from: "A".to_string(), to: "C".to_string(), weight: Some(5.0) },
+                super::super::Edge { from: "A".to_string(), to: "B".to_string(), weight: Some(1.0) },
+                super::super::Edge { from: "B".to_string(), to: "C".to_string(), weight: Some(1.0) },
+            ],
+        };
+
+        let result = compute_k_shortest_paths(&graph, "A", "C", 2);
+        assert_eq!(result.paths.len(), 2);
+        assert_eq!(result.paths[0], vec!["A".to_string(), "B".to_string(), "C".to_string()]);
+        assert_eq!(result.paths[1], vec!["A".to_string(), "C".to_string()]);
+    }
+
+    #[test]
+    fn test_dijkstra_prefers_lower_weight_over_fewer_hops() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            edges: vec![
+                super::super::Edge {
+                    from: "A".to_string(),
+                    to: "C".to_string(),
+                    weight: Some(10.0),
+                },
+                super::super::Edge {
+                    from: "A".to_string(),
+                    to: "B".to_string(),
+                    weight: Some(1.0),
+                },
+                super::super::Edge {
+                    from: "B".to_string(),
+                    to: "C".to_string(),
+                    weight: Some(1.0),
+                },
+            ],
+        };
+
+        // BFS would pick the direct A->C hop; Dijkstra should prefer A->B->C
+        let result = dijkstra_shortest_path(&graph, "A", "C");
+        assert!(result.exists);
+        assert_eq!(result.path, vec!["A".to_string(), "B".to_string(), "C".to_string()]);
+        assert_eq!(result.distance, Some(2.0));
+    }
+
+    #[test]
+    fn test_dijkstra_no_path() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            edges: vec![super::super::Edge {
+                from: "A".to_string(),
+                to: "B".to_string(),
+                weight: Some(1.0),
+            }],
+        };
+
+        let result = dijkstra_shortest_path(&graph, "A", "C");
+        assert!(!result.exists);
+    }
+
+    #[test]
+    fn test_dijkstra_rejects_negative_weights() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string()],
+            edges: vec![super::super::Edge {
+                from: "A".to_string(),
+                to: "B".to_string(),
+                weight: Some(-1.0),
+            }],
+        };
+
+        let result = dijkstra_shortest_path(&graph, "A", "B");
+        assert!(!result.exists);
+    }
+
     #[test]
     fn test_find_path_exists() {
         let graph = Graph {
@@ -0,0 +1,144 @@
+use super::{Graph, RunsResult};
+use std::collections::{HashMap, HashSet};
+
+/// Collapse a DAG into maximal linear chains under a node filter
+///
+/// Processes nodes in topological order (so a run is always started from its
+/// earliest unconsumed member) and greedily extends each run forward through
+/// successors that are both allowed and unambiguous - i.e. the successor has
+/// exactly one predecessor in the whole graph, so it can only continue the
+/// chain started by the current node. An empty `allowed` set means every node
+/// is eligible. Returns an empty result if the graph is cyclic, since runs
+/// rely on a topological order.
+pub fn compute_runs(graph: &Graph, allowed: &HashSet<String>) -> RunsResult {
+    let topo = super::compute_topological_sort(graph);
+    if topo.has_cycle {
+        return RunsResult { runs: vec![] };
+    }
+
+    let allowed_set: HashSet<String> = if allowed.is_empty() {
+        graph.nodes.iter().cloned().collect()
+    } else {
+        allowed.clone()
+    };
+
+    let mut adjacency: HashMap<String, Vec<String>> = HashMap::new();
+    let mut in_degree: HashMap<String, usize> = HashMap::new();
+    for node in &graph.nodes {
+        adjacency.insert(node.clone(), Vec::new());
+        in_degree.insert(node.clone(), 0);
+    }
+    for edge in &graph.edges {
+        adjacency
+            .entry(edge.from.clone())
+            .or_insert_with(Vec::new)
+            .push(edge.to.clone());
+        *in_degree.entry(edge.to.clone()).or_insert(0) += 1;
+    }
+
+    let mut consumed: HashSet<String> = HashSet::new();
+    let mut runs: Vec<Vec<String>> = Vec::new();
+
+    for node in &topo.sorted {
+        if !allowed_set.contains(node) || consumed.contains(node) {
+            continue;
+        }
+
+        let mut run = vec![node.clone()];
+        consumed.insert(node.clone());
+        let mut current = node.clone();
+
+        loop {
+            let next = adjacency.get(&current).and_then(|successors| {
+                successors.iter().find(|successor| {
+                    allowed_set.contains(*successor)
+                        && !consumed.contains(*successor)
+                        && in_degree.get(*successor).copied().unwrap_or(0) == 1
+                })
+            });
+
+            match next.cloned() {
+                Some(successor) => {
+                    run.push(successor.clone());
+                    consumed.insert(successor.clone());
+                    current = successor;
+                }
+                None => break,
+            }
+        }
+
+        runs.push(run);
+    }
+
+    RunsResult { runs }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::Edge;
+
+    #[test]
+    fn test_collect_runs_chains_single_in_single_out_nodes() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string(), "D".to_string()],
+            edges: vec![
+                Edge { from: "A".to_string(), to: "B".to_string(), weight: None },
+                Edge { from: "B".to_string(), to: "C".to_string(), weight: None },
+                Edge { from: "C".to_string(), to: "D".to_string(), weight: None },
+            ],
+        };
+
+        let result = compute_runs(&graph, &HashSet::new());
+        assert_eq!(result.runs.len(), 1);
+        assert_eq!(
+            result.runs[0],
+            vec!["A".to_string(), "B".to_string(), "C".to_string(), "D".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_collect_runs_splits_at_merge_point() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            edges: vec![
+                Edge { from: "A".to_string(), to: "C".to_string(), weight: None },
+                Edge { from: "B".to_string(), to: "C".to_string(), weight: None },
+            ],
+        };
+
+        // C has in-degree 2, so neither A nor B can unambiguously continue
+        // a run into it - every node ends up in its own singleton run.
+        let result = compute_runs(&graph, &HashSet::new());
+        assert_eq!(result.runs.len(), 3);
+    }
+
+    #[test]
+    fn test_collect_runs_respects_allowed_filter() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            edges: vec![
+                Edge { from: "A".to_string(), to: "B".to_string(), weight: None },
+                Edge { from: "B".to_string(), to: "C".to_string(), weight: None },
+            ],
+        };
+
+        let allowed: HashSet<String> = vec!["A".to_string(), "B".to_string()].into_iter().collect();
+        let result = compute_runs(&graph, &allowed);
+        assert_eq!(result.runs, vec![vec!["A".to_string(), "B".to_string()]]);
+    }
+
+    #[test]
+    fn test_collect_runs_empty_for_cyclic_graph() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string()],
+            edges: vec![
+                Edge { from: "A".to_string(), to: "B".to_string(), weight: None },
+                Edge { from: "B".to_string(), to: "A".to_string(), weight: None },
+            ],
+        };
+
+        let result = compute_runs(&graph, &HashSet::new());
+        assert!(result.runs.is_empty());
+    }
+}
@@ -0,0 +1,239 @@
+use super::{Edge, Graph, SccResult};
+use std::collections::{HashMap, HashSet};
+
+/// Compute strongly connected components using an iterative Tarjan's algorithm
+///
+/// The DFS is implemented iteratively (with an explicit call stack of
+/// `(node, next_neighbor_index)` frames) rather than recursively, since WASM
+/// has a limited call stack and graphs may be deep.
+pub fn compute_strongly_connected_components(graph: &Graph) -> SccResult {
+    let mut adjacency: HashMap<String, Vec<String>> = HashMap::new();
+    for node in &graph.nodes {
+        adjacency.insert(node.clone(), Vec::new());
+    }
+    for edge in &graph.edges {
+        adjacency
+            .entry(edge.from.clone())
+            .or_insert_with(Vec::new)
+            .push(edge.to.clone());
+    }
+
+    let mut next_index = 0usize;
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut lowlink: HashMap<String, usize> = HashMap::new();
+    let mut on_stack: HashSet<String> = HashSet::new();
+    let mut tarjan_stack: Vec<String> = Vec::new();
+    let mut components: Vec<Vec<String>> = Vec::new();
+
+    for start in &graph.nodes {
+        if index.contains_key(start) {
+            continue;
+        }
+
+        // Each call-stack frame is (node, index of the next neighbor to visit)
+        let mut call_stack: Vec<(String, usize)> = vec![(start.clone(), 0)];
+
+        while let Some((node, mut neighbor_pos)) = call_stack.pop() {
+            if neighbor_pos == 0 {
+                index.insert(node.clone(), next_index);
+                lowlink.insert(node.clone(), next_index);
+                next_index += 1;
+                tarjan_stack.push(node.clone());
+                on_stack.insert(node.clone());
+            }
+
+            let neighbors = adjacency.get(&node).map(Vec::as_slice).unwrap_or(&[]);
+            let mut recursed = false;
+
+            while neighbor_pos < neighbors.len() {
+                let neighbor = &neighbors[neighbor_pos];
+                neighbor_pos += 1;
+
+                if !index.contains_key(neighbor) {
+                    call_stack.push((node.clone(), neighbor_pos));
+                    call_stack.push((neighbor.clone(), 0));
+                    recursed = true;
+                    break;
+                } else if on_stack.contains(neighbor) {
+                    let neighbor_index = index[neighbor];
+                    let current_low = lowlink[&node];
+                    lowlink.insert(node.clone(), current_low.min(neighbor_index));
+                }
+            }
+
+            if recursed {
+                continue;
+            }
+
+            if lowlink[&node] == index[&node] {
+                let mut component = Vec::new();
+                loop {
+                    let member = tarjan_stack.pop().expect("SCC root must be on the stack");
+                    on_stack.remove(&member);
+                    let is_root = member == node;
+                    component.push(member);
+                    if is_root {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+
+            // Propagate this node's lowlink up to its caller, mirroring the
+            // `lowlink[u] = min(lowlink[u], lowlink[v])` update for tree edges.
+            if let Some((parent, _)) = call_stack.last() {
+                let child_low = lowlink[&node];
+                let parent_low = lowlink[parent];
+                lowlink.insert(parent.clone(), parent_low.min(child_low));
+            }
+        }
+    }
+
+    SccResult { components }
+}
+
+/// Collapse each strongly connected component into a single super-node,
+/// producing a new (necessarily acyclic) `Graph` that can feed directly into
+/// `compute_topological_sort`.
+pub fn condense_graph(graph: &Graph) -> Graph {
+    let scc = compute_strongly_connected_components(graph);
+
+    let mut component_of: HashMap<String, String> = HashMap::new();
+    let mut super_nodes: Vec<String> = Vec::new();
+
+    for component in &scc.components {
+        let mut members = component.clone();
+        members.sort();
+        let super_node = members.join(",");
+        super_nodes.push(super_node.clone());
+        for member in component {
+            component_of.insert(member.clone(), super_node.clone());
+        }
+    }
+
+    let mut seen_edges = HashSet::new();
+    let mut edges: Vec<Edge> = Vec::new();
+
+    for edge in &graph.edges {
+        let (from_super, to_super) = match (component_of.get(&edge.from), component_of.get(&edge.to)) {
+            (Some(from_super), Some(to_super)) => (from_super, to_super),
+            _ => continue,
+        };
+        if from_super != to_super && seen_edges.insert((from_super.clone(), to_super.clone())) {
+            edges.push(Edge {
+                from: from_super.clone(),
+                to: to_super.clone(),
+                weight: edge.weight,
+            });
+        }
+    }
+
+    Graph {
+        nodes: super_nodes,
+        edges,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scc_single_cycle() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            edges: vec![
+                Edge { from: "A".to_string(), to: "B".to_string(), weight: None },
+                Edge { from: "B".to_string(), to: "C".to_string(), weight: None },
+                Edge { from: "C".to_string(), to: "A".to_string(), weight: None },
+            ],
+        };
+
+        let result = compute_strongly_connected_components(&graph);
+        assert_eq!(result.components.len(), 1);
+        assert_eq!(result.components[0].len(), 3);
+    }
+
+    #[test]
+    fn test_scc_all_singletons_for_dag() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            edges: vec![
+                Edge { from: "A".to_string(), to: "B".to_string(), weight: None },
+                Edge { from: "B".to_string(), to: "C".to_string(), weight: None },
+            ],
+        };
+
+        let result = compute_strongly_connected_components(&graph);
+        assert_eq!(result.components.len(), 3);
+    }
+
+    #[test]
+    fn test_scc_two_components() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string(), "D".to_string()],
+            edges: vec![
+                Edge { from: "A".to_string(), to: "B".to_string(), weight: None },
+                Edge { from: "B".to_string(), to: "A".to_string(), weight: None },
+                Edge { from: "C".to_string(), to: "D".to_string(), weight: None },
+                Edge { from: "D".to_string(), to: "C".to_string(), weight: None },
+                Edge { from: "A".to_string(), to: "C".to_string(), weight: None },
+            ],
+        };
+
+        let result = compute_strongly_connected_components(&graph);
+        assert_eq!(result.components.len(), 2);
+    }
+
+    #[test]
+    fn test_scc_tolerates_edge_to_node_outside_nodes_list() {
+        // "B" is never in `nodes` and never appears as an edge's `from`, so it
+        // has no `adjacency` entry of its own; this must not panic.
+        let graph = Graph {
+            nodes: vec!["A".to_string()],
+            edges: vec![Edge { from: "A".to_string(), to: "B".to_string(), weight: None }],
+        };
+
+        let result = compute_strongly_connected_components(&graph);
+        assert_eq!(result.components.len(), 2);
+    }
+
+    #[test]
+    fn test_condense_produces_acyclic_graph() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string(), "D".to_string()],
+            edges: vec![
+                Edge { from: "A".to_string(), to: "B".to_string(), weight: None },
+                Edge { from: "B".to_string(), to: "A".to_string(), weight: None },
+                Edge { from: "B".to_string(), to: "C".to_string(), weight: None },
+                Edge { from: "C".to_string(), to: "D".to_string(), weight: None },
+            ],
+        };
+
+        let condensed = condense_graph(&graph);
+        assert_eq!(condensed.nodes.len(), 3);
+
+        let topo = super::super::compute_topological_sort(&condensed);
+        assert!(!topo.has_cycle);
+        assert_eq!(topo.sorted.len(), 3);
+    }
+
+    #[test]
+    fn test_condense_tolerates_edges_unreachable_from_nodes_list() {
+        // "B" is reachable from "A" and gets its own component, but "X" and
+        // "Y" are never in `nodes` and never reached from any declared node,
+        // so they have no `component_of` entry; the dangling edge must be
+        // dropped rather than panicking.
+        let graph = Graph {
+            nodes: vec!["A".to_string()],
+            edges: vec![
+                Edge { from: "A".to_string(), to: "B".to_string(), weight: None },
+                Edge { from: "X".to_string(), to: "Y".to_string(), weight: None },
+            ],
+        };
+
+        let condensed = condense_graph(&graph);
+        assert_eq!(condensed.nodes.len(), 2);
+        assert_eq!(condensed.edges.len(), 1);
+    }
+}
@@ -50,9 +50,68 @@ pub fn compute_topological_sort(graph: &Graph) -> TopologicalSortResult {
     // Check for cycle: if sorted length < total nodes, there's a cycle
     let has_cycle = sorted.len() < graph.nodes.len();
 
+    let (remaining, cycle) = if has_cycle {
+        let sorted_set: std::collections::HashSet<&String> = sorted.iter().collect();
+        let remaining: Vec<String> = graph
+            .nodes
+            .iter()
+            .filter(|node| !sorted_set.contains(node))
+            .cloned()
+            .collect();
+        let cycle = extract_cycle_from_residual(graph, &remaining);
+        (Some(remaining), Some(cycle))
+    } else {
+        (None, None)
+    };
+
     TopologicalSortResult {
         sorted,
         has_cycle,
+        remaining,
+        cycle,
+    }
+}
+
+/// Extract one concrete cycle from the residual subgraph left over when Kahn's
+/// algorithm stalls, i.e. the nodes in `TopologicalSortResult::remaining`
+///
+/// Follows incoming edges among `remaining` nodes (every node here kept a
+/// non-zero in-degree, so when every edge endpoint is in `graph.nodes` it has
+/// at least one predecessor in the residual set) until a node repeats, then
+/// returns the loop in edge order - mirroring how `tsort`-style tools report
+/// "input contains a loop". Returns an empty vector if the walk reaches a node
+/// with no residual predecessor.
+pub fn extract_cycle_from_residual(graph: &Graph, remaining: &[String]) -> Vec<String> {
+    if remaining.is_empty() {
+        return vec![];
+    }
+
+    let remaining_set: std::collections::HashSet<&String> = remaining.iter().collect();
+
+    let mut predecessors: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in &graph.edges {
+        if remaining_set.contains(&edge.from) && remaining_set.contains(&edge.to) {
+            predecessors.entry(&edge.to).or_insert_with(Vec::new).push(&edge.from);
+        }
+    }
+
+    let mut visited: Vec<&str> = Vec::new();
+    let mut position: HashMap<&str, usize> = HashMap::new();
+    let mut current: &str = &remaining[0];
+
+    loop {
+        if let Some(&start) = position.get(current) {
+            // Walked backwards, so reverse to list the loop along its edges
+            return visited[start..].iter().rev().map(|s| s.to_string()).collect();
+        }
+
+        position.insert(current, visited.len());
+        visited.push(current);
+
+        current = match predecessors.get(current).and_then(|prev| prev.first()) {
+            Some(prev) => prev,
+            None => return vec![],
+        };
     }
 }
 
@@ -110,4 +169,54 @@ mod tests {
         let result = compute_topological_sort(&graph);
         assert!(result.has_cycle);
     }
+
+    #[test]
+    fn test_compute_topological_sort_reports_remaining_nodes() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            edges: vec![
+                super::super::Edge { from: "A".to_string(), to: "B".to_string(), weight: None },
+                super::super::Edge { from: "B".to_string(), to: "A".to_string(), weight: None },
+            ],
+        };
+
+        let result = compute_topological_sort(&graph);
+        assert!(result.has_cycle);
+        let remaining = result.remaining.expect("cyclic result should report remaining nodes");
+        assert!(remaining.contains(&"A".to_string()));
+        assert!(remaining.contains(&"B".to_string()));
+        assert!(!remaining.contains(&"C".to_string()));
+    }
+
+    #[test]
+    fn test_compute_topological_sort_no_remaining_when_acyclic() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string()],
+            edges: vec![super::super::Edge { from: "A".to_string(), to: "B".to_string(), weight: None }],
+        };
+
+        let result = compute_topological_sort(&graph);
+        assert!(result.remaining.is_none());
+    }
+
+    #[test]
+    fn test_extract_cycle_from_residual() {
+        let graph = Graph {
+            nodes: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            edges: vec![
+                super::super::Edge { from: "A".to_string(), to: "B".to_string(), weight: None },
+                super::super::Edge { from: "B".to_string(), to: "C".to_string(), weight: None },
+                super::super::Edge { from: "C".to_string(), to: "A".to_string(), weight: None },
+            ],
+        };
+
+        let result = compute_topological_sort(&graph);
+        let remaining = result.remaining.expect("cyclic result should report remaining nodes");
+        let cycle = extract_cycle_from_residual(&graph, &remaining);
+
+        assert_eq!(cycle.len(), 3);
+        assert!(cycle.contains(&"A".to_string()));
+        assert!(cycle.contains(&"B".to_string()));
+        assert!(cycle.contains(&"C".to_string()));
+    }
 }